@@ -1,3 +1,5 @@
+#![allow(clippy::needless_return)]
+
 extern crate genes;
 
 use genes::{OptimizerBuilder, Target, genes::Genes};
@@ -1,3 +1,5 @@
+#![allow(clippy::needless_return, clippy::identity_op)]
+
 extern crate genes;
 use genes::{OptimizerBuilder, Target, genes::Genes};
 
@@ -6,6 +8,7 @@ use std::{env, path::Path};
 use image::GenericImageView;
 
 
+#[derive(Clone)]
 struct ApproxImage {
     actual: image::DynamicImage
 }
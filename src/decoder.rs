@@ -0,0 +1,55 @@
+/// maps a bitstring into a vector of bounded, real-valued dimensions.
+///
+/// the genome is split into `n_dim` consecutive groups of `n_bits_per_group`
+/// bits; each group is read as an unsigned integer and linearly rescaled into
+/// its dimension's `(lower, upper)` bound, giving `Target` implementations
+/// real coordinates to score against instead of raw bits.
+pub struct Decoder {
+    n_dim: u32,
+    n_bits_per_group: u32,
+    bounds: Vec<(f64, f64)>
+}
+
+impl Decoder {
+    pub fn new(n_dim: u32, n_bits_per_group: u32, bounds: Vec<(f64, f64)>) -> Decoder {
+        assert_eq!(
+            bounds.len(), n_dim as usize,
+            "'bounds' must supply one (lower, upper) pair per dimension"
+        );
+        assert!(
+            (1..=63).contains(&n_bits_per_group),
+            "'n_bits_per_group' must be between 1 and 63, got {n_bits_per_group}"
+        );
+
+        return Decoder { n_dim, n_bits_per_group, bounds };
+    }
+
+    pub fn n_dim(&self) -> u32 {
+        return self.n_dim;
+    }
+
+    pub fn n_bits_per_group(&self) -> u32 {
+        return self.n_bits_per_group;
+    }
+
+    pub fn bounds(&self) -> &[(f64, f64)] {
+        return &self.bounds;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "'n_bits_per_group' must be between 1 and 63")]
+    fn rejects_zero_bits_per_group() {
+        Decoder::new(1, 0, vec![(0.0, 1.0)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "'n_bits_per_group' must be between 1 and 63")]
+    fn rejects_64_bits_per_group() {
+        Decoder::new(1, 64, vec![(0.0, 1.0)]);
+    }
+}
@@ -1,3 +1,5 @@
+use crate::decoder::Decoder;
+
 #[derive(Clone)]
 pub struct Genes {
     inner: Vec<u8>
@@ -66,6 +68,12 @@ impl Genes {
         }
     }
 
+    /// the raw genome bytes, e.g. as a cache key
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] {
+        return &self.inner;
+    }
+
     /// convenience method to get the nth 8-bit part of genes
     pub fn g8(&self, loc: usize) -> u8 {
         if loc < self.inner.len() {
@@ -82,9 +90,8 @@ impl Genes {
         let loc = loc * adj_factor; // the Nth 16-bit slice is begins at 2N
 
         if loc < self.inner.len() - (adj_factor - 1) {
-            return 0u16 
-                | ((self.inner[loc] as u16) << 8)
-                | ((self.inner[loc + 1] as u16));
+            return ((self.inner[loc] as u16) << 8)
+                | (self.inner[loc + 1] as u16);
         } else {
             return 0;
         }
@@ -97,11 +104,10 @@ impl Genes {
         let loc = loc * adj_factor; // the Nth 32-bit slice is begins at 4N
 
         if loc < self.inner.len() - (adj_factor - 1) {
-            return 0u32
-                | ((self.inner[loc] as u32) << 24)
+            return ((self.inner[loc] as u32) << 24)
                 | ((self.inner[loc + 1] as u32) << 16)
                 | ((self.inner[loc + 2] as u32) << 8)
-                | ((self.inner[loc + 3] as u32));
+                | (self.inner[loc + 3] as u32);
         } else {
             return 0;
         }
@@ -114,15 +120,14 @@ impl Genes {
         let loc = loc * adj_factor; // the Nth 64-bit slice is begins at 8N
 
         if loc < self.inner.len() - (adj_factor - 1) {
-            return 0u64
-                | ((self.inner[loc] as u64) << 56)
+            return ((self.inner[loc] as u64) << 56)
                 | ((self.inner[loc + 1] as u64) << 48)
                 | ((self.inner[loc + 2] as u64) << 40)
                 | ((self.inner[loc + 3] as u64) << 32)
                 | ((self.inner[loc + 4] as u64) << 24)
                 | ((self.inner[loc + 5] as u64) << 16)
                 | ((self.inner[loc + 6] as u64) << 8)
-                | ((self.inner[loc + 7] as u64));
+                | (self.inner[loc + 7] as u64);
         } else {
             return 0;
         }
@@ -135,8 +140,7 @@ impl Genes {
         let loc = loc * adj_factor; // the Nth 128-bit slice is begins at 16N
 
         if loc < self.inner.len() - (adj_factor - 1) {
-            return 0u128
-                | ((self.inner[loc] as u128) << 120)
+            return ((self.inner[loc] as u128) << 120)
                 | ((self.inner[loc + 1] as u128) << 112)
                 | ((self.inner[loc + 2] as u128) << 104)
                 | ((self.inner[loc + 3] as u128) << 96)
@@ -151,9 +155,64 @@ impl Genes {
                 | ((self.inner[loc + 12] as u128) << 24)
                 | ((self.inner[loc + 13] as u128) << 16)
                 | ((self.inner[loc + 14] as u128) << 8)
-                | ((self.inner[loc + 15] as u128));
+                | (self.inner[loc + 15] as u128);
         } else {
             return 0;
         }
     }
+
+    /// decode the genome into `decoder.n_dim()` bounded real values, reading
+    /// `decoder.n_bits_per_group()` consecutive bits per dimension
+    pub fn decode(&self, decoder: &Decoder) -> Vec<f64> {
+        let bits = decoder.n_bits_per_group();
+        let max = ((1u64 << bits) - 1) as f64;
+
+        let mut values = Vec::with_capacity(decoder.n_dim() as usize);
+
+        for dim in 0..decoder.n_dim() {
+            let start = dim * bits;
+
+            let mut v: u64 = 0;
+            for b in 0..bits {
+                v = (v << 1) | self.get(start + b) as u64;
+            }
+
+            let (lower, upper) = decoder.bounds()[dim as usize];
+            values.push(lower + (v as f64 / max) * (upper - lower));
+        }
+
+        return values;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder::Decoder;
+
+    #[test]
+    fn decode_rescales_bit_groups_into_bounds() {
+        // 2 dims of 4 bits each: dim 0 reads bits [0,4) = 0000 (min), dim 1
+        // reads bits [4,8) = 1111 (max)
+        let decoder = Decoder::new(2, 4, vec![(-1.0, 1.0), (0.0, 10.0)]);
+        let genes = Genes::new_with_genes(vec![0b1111_0000]);
+
+        let values = genes.decode(&decoder);
+
+        assert_eq!(values.len(), 2);
+        assert!((values[0] - (-1.0)).abs() < 1e-9);
+        assert!((values[1] - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn decode_rescales_to_two_thirds() {
+        // 1 dim of 2 bits: bit 0 (value 1) then bit 1 (value 0) decode to
+        // v = 2, i.e. 2/3 of the way between bounds
+        let decoder = Decoder::new(1, 2, vec![(0.0, 9.0)]);
+        let genes = Genes::new_with_genes(vec![0b0000_0001]);
+
+        let values = genes.decode(&decoder);
+
+        assert!((values[0] - 6.0).abs() < 1e-9);
+    }
 }
\ No newline at end of file
@@ -8,6 +8,7 @@ pub(crate)struct Individual {
 impl Individual {
     /// create a new Individual with all genes
     /// set to zero
+    #[allow(dead_code)]
     pub fn new(n: u32) -> Individual {
         return Individual {
             score: 0.0,
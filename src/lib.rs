@@ -1,46 +1,89 @@
+#![allow(clippy::needless_return)]
+
 use core::f64;
-use std::usize;
+use std::collections::{HashMap, HashSet, VecDeque};
 use rand::{Rng, SeedableRng};
 use rand::rngs::SmallRng;
 
+pub mod decoder;
 pub mod genes;
 pub mod individual;
+pub mod multi;
+pub mod mutation;
 pub mod options;
+pub mod stop;
 
+use decoder::Decoder;
 use genes::*;
 use individual::*;
+use mutation::MutationRate;
+use stop::StopCriterion;
 
 const DEFAULT_POP_SIZE: u32 = 100;
-const DEFAULT_MUT_RATE: f64 = 0.05;
-const DEFAULT_TGT_TYPE: options::TargetType = options::TargetType::MAXIMIZE;
+const DEFAULT_MUT_RATE: MutationRate = MutationRate::Constant(0.05);
+const DEFAULT_TGT_TYPE: options::TargetType = options::TargetType::Maximize;
 const DEFAULT_SEL_METH: options::SelectionMethod = options::SelectionMethod::Weighted;
-const DEFAULT_CRS_METH: options::CrossoverMethod = options::CrossoverMethod::BARRIER;
+const DEFAULT_CRS_METH: options::CrossoverMethod = options::CrossoverMethod::Barrier;
+const DEFAULT_TOURNAMENT_K: u32 = 3;
+const MAX_PROGRESS_HISTORY: usize = 256;
 
+#[cfg(not(feature = "parallel"))]
 pub trait Target {
     fn score(&mut self, genes: &Genes) -> f64;
 }
 
+// the parallel evaluation path scores the population on a rayon thread pool:
+// each worker clones the target (`Clone`) so it can hold its own `&mut self`
+// scorer, and the original target is shared across threads to clone from
+// (`Sync`)
+#[cfg(feature = "parallel")]
+pub trait Target: Clone + Sync {
+    fn score(&mut self, genes: &Genes) -> f64;
+}
+
 /// API entrypoint
 pub struct Optimizer<T: Target> {
     population: Vec<Individual>,
     n: u32,
-    mutation_rate: f64,
+    mutation_rate: MutationRate,
     target: T,
     target_type: options::TargetType,
     selection_method: options::SelectionMethod,
     crossover_method: options::CrossoverMethod,
+    tournament_k: u32,
+    /// caches scores by genome bytes, so an individual whose genes are
+    /// unchanged since last generation isn't re-evaluated; `None` when
+    /// `OptimizerBuilder::cache` wasn't enabled
+    cache: Option<HashMap<Vec<u8>, f64>>,
+    /// the bitstring-to-coordinates mapping supplied via
+    /// `OptimizerBuilder::decoder`, if any; not applied during scoring, it's
+    /// stored for a `Target` implementation to pull via `Optimizer::decoder`
+    /// and decode genomes itself (see `Genes::decode`)
+    decoder: Option<Decoder>,
+    #[cfg(feature = "parallel")]
+    parallel: bool,
+    #[cfg(feature = "parallel")]
+    thread_pool: Option<rayon::ThreadPool>,
+    generation: u32,
+    progress_history: VecDeque<f64>,
     rng: SmallRng
 }
 
 impl<T: Target> Optimizer<T>{
+    #[allow(clippy::too_many_arguments)]
     fn new(
         size: u32,
         n: u32,
-        mutation_rate: f64,
+        mutation_rate: MutationRate,
         target: T,
         target_type: options::TargetType,
         selection_method: options::SelectionMethod,
-        crossover_method: options::CrossoverMethod
+        crossover_method: options::CrossoverMethod,
+        tournament_k: u32,
+        cache: bool,
+        decoder: Option<Decoder>,
+        #[cfg(feature = "parallel")] parallel: bool,
+        #[cfg(feature = "parallel")] threads: Option<usize>
     ) -> Optimizer<T> {
         let mut population = Vec::with_capacity(size as usize);
 
@@ -57,6 +100,14 @@ impl<T: Target> Optimizer<T>{
             population.push(Individual::new_with_genes(genes));
         }
 
+        #[cfg(feature = "parallel")]
+        let thread_pool = threads.map(|n| {
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(n)
+                .build()
+                .expect("failed to build rayon thread pool")
+        });
+
         // reuse the rng created above
         return Optimizer {
             population,
@@ -66,6 +117,15 @@ impl<T: Target> Optimizer<T>{
             target_type,
             selection_method,
             crossover_method,
+            tournament_k,
+            cache: if cache { Some(HashMap::new()) } else { None },
+            decoder,
+            #[cfg(feature = "parallel")]
+            parallel,
+            #[cfg(feature = "parallel")]
+            thread_pool,
+            generation: 0,
+            progress_history: VecDeque::with_capacity(MAX_PROGRESS_HISTORY),
             rng
         };
     }
@@ -77,54 +137,241 @@ impl<T: Target> Optimizer<T>{
         }
     }
 
+    /// evolve until `criterion` signals that the population has converged
+    pub fn run(&mut self, mut criterion: impl StopCriterion) {
+        loop {
+            self.step();
+
+            let best_score = *self.progress_history.back().unwrap();
+            if criterion.should_stop(self.generation, best_score, &self.progress_history) {
+                break;
+            }
+        }
+    }
+
+    /// the number of generations evolved so far, counting every `step`
+    /// (whether called directly or via `evolve` or `run`)
+    pub fn generation(&self) -> u32 {
+        return self.generation;
+    }
+
+    /// the decoder supplied via `OptimizerBuilder::decoder`, if any
+    pub fn decoder(&self) -> Option<&Decoder> {
+        return self.decoder.as_ref();
+    }
+
     /// perform a single step of evolution
     pub fn step(&mut self) {
-        for individual in self.population.iter_mut() {
-            individual.score = self.target.score(individual.genes());
-        }
+        self.evaluate_population();
+
+        // sort population by fitness, best individual first
+        let target_type = self.target_type;
+        self.population.sort_by(|a, b| match target_type {
+            options::TargetType::Minimize => a.score.partial_cmp(&b.score).unwrap(),
+            options::TargetType::Maximize => b.score.partial_cmp(&a.score).unwrap(),
+        });
 
-        // sort population by fitness
-        self.population.sort_by(
-            |a,b | a.score.partial_cmp(&b.score).unwrap()
-        );
-        
         // drop the lower scoring individuals
         let keep_percent = 0.5;
         let current_size = self.population.len();
         let keep = (current_size as f64 * keep_percent) as usize;
 
+        // a dropped individual's genome may be shared with a surviving one
+        // (duplicate genomes are common once the population converges), so
+        // only evict cache entries no survivor still holds
+        let surviving_genomes: HashSet<Vec<u8>> = if self.cache.is_some() {
+            self.population[..keep].iter().map(|individual| individual.genes().as_bytes().to_vec()).collect()
+        } else {
+            HashSet::new()
+        };
+
         for individual in self.population[keep..].iter_mut() {
+            if let Some(cache) = self.cache.as_mut() {
+                if !surviving_genomes.contains(individual.genes().as_bytes()) {
+                    cache.remove(individual.genes().as_bytes());
+                }
+            }
             individual.genes.wipe();
         }
 
-        // recreate by randomly matching remaining population and crossing-over
+        // recreate by selecting parents from the survivors and crossing-over
         for idx in keep..current_size {
-            let parent1_idx = self.rng.gen_range(0..keep);
-            let mut parent2_idx = self.rng.gen_range(0..keep);
-            while parent1_idx == parent2_idx { parent2_idx = self.rng.gen_range(0..keep); }
+            let parent1_idx = self.select_parent(keep);
+            let mut parent2_idx = self.select_parent(keep);
+            while parent1_idx == parent2_idx { parent2_idx = self.select_parent(keep); }
+
+            let parent1 = self.population[parent1_idx].genes.clone();
+            let parent2 = self.population[parent2_idx].genes.clone();
+            *self.population[idx].genes_mut() = self.crossover(&parent1, &parent2);
+        }
 
-            let parent1 = &self.population[parent1_idx].genes.clone();
-            let parent2 = &self.population[parent2_idx].genes.clone();
-            *self.population[idx].genes_mut() = self.crossover(parent1, parent2);
+        self.progress_history.push_back(self.population[0].score);
+        if self.progress_history.len() > MAX_PROGRESS_HISTORY {
+            self.progress_history.pop_front();
+        }
+
+        self.generation += 1;
+    }
+
+    /// look up `genes` in the cache, falling back to `target.score` and
+    /// recording the result on a miss; scores unconditionally if caching is
+    /// disabled (`cache` is `None`)
+    fn cached_score(cache: &mut Option<HashMap<Vec<u8>, f64>>, target: &mut T, genes: &Genes) -> f64 {
+        return match cache {
+            Some(cache) => {
+                if let Some(&score) = cache.get(genes.as_bytes()) {
+                    score
+                } else {
+                    let score = target.score(genes);
+                    cache.insert(genes.as_bytes().to_vec(), score);
+                    score
+                }
+            },
+            None => target.score(genes),
+        };
+    }
+
+    /// score every individual in the population, serially by default or, with
+    /// the `parallel` feature enabled and `self.parallel` set, across a rayon
+    /// thread pool (each worker clones the target for its own `&mut` access).
+    /// consults `self.cache` first when caching is enabled.
+    #[cfg(not(feature = "parallel"))]
+    fn evaluate_population(&mut self) {
+        for individual in self.population.iter_mut() {
+            individual.score = Self::cached_score(&mut self.cache, &mut self.target, &individual.genes);
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    fn evaluate_population(&mut self) {
+        if !self.parallel {
+            for individual in self.population.iter_mut() {
+                individual.score = Self::cached_score(&mut self.cache, &mut self.target, &individual.genes);
+            }
+            return;
+        }
+
+        use rayon::prelude::*;
+
+        // cache hits are looked up up front (shared immutable access is fine
+        // across threads); only genuine misses call into `target.score` on
+        // the pool, and their results are recorded back into the cache
+        // afterwards, serially, to avoid a concurrent `HashMap`
+        let target = &self.target;
+        let cache = &self.cache;
+        let population = &mut self.population;
+        let mut eval = || {
+            population.par_iter_mut().for_each_init(
+                || target.clone(),
+                |local_target, individual| {
+                    let hit = cache.as_ref().and_then(|c| c.get(individual.genes().as_bytes()).copied());
+                    individual.score = match hit {
+                        Some(score) => score,
+                        None => local_target.score(individual.genes()),
+                    };
+                },
+            );
+        };
+
+        match &self.thread_pool {
+            Some(pool) => pool.install(eval),
+            None => eval(),
+        }
+
+        if let Some(cache) = self.cache.as_mut() {
+            for individual in self.population.iter() {
+                cache.entry(individual.genes().as_bytes().to_vec()).or_insert(individual.score);
+            }
         }
     }
 
     /// the genes of the best scoring individual
     pub fn best(&mut self) -> &Genes {
-        let mut best_score: f64 = -1.0;
-        let mut best: &Individual = &self.population[0];
+        let mut best_idx = 0;
+        let mut best_score = Self::cached_score(&mut self.cache, &mut self.target, &self.population[0].genes);
+
+        for idx in 1..self.population.len() {
+            let score = Self::cached_score(&mut self.cache, &mut self.target, &self.population[idx].genes);
+            let better = match self.target_type {
+                options::TargetType::Minimize => score < best_score,
+                options::TargetType::Maximize => score > best_score,
+            };
+
+            if better {
+                best_score = score;
+                best_idx = idx;
+            }
+        }
+
+        return self.population[best_idx].genes();
+    }
+
+    /// select a parent index from the surviving `[0, keep)` population
+    /// according to `self.selection_method`. assumes the population is
+    /// sorted best-first, i.e. lower index is a better individual.
+    fn select_parent(&mut self, keep: usize) -> usize {
+        return match self.selection_method {
+            options::SelectionMethod::Equal => self.rng.gen_range(0..keep),
+            options::SelectionMethod::Weighted => self.select_weighted(keep),
+            options::SelectionMethod::EqualTournament => self.select_k(keep, false),
+            options::SelectionMethod::WeightedTournament => self.select_k(keep, true),
+        };
+    }
+
+    /// fitness-rank-proportional selection over the survivors: the best
+    /// individual (index 0) is weighted `keep`, the worst survivor weighted 1
+    fn select_weighted(&mut self, keep: usize) -> usize {
+        let total: f64 = (1..=keep).sum::<usize>() as f64;
+        let mut pick = self.rng.gen_range(0.0..total);
+
+        for idx in 0..keep {
+            let weight = (keep - idx) as f64;
+            if pick < weight { return idx; }
+            pick -= weight;
+        }
 
-        for individual in self.population.iter() {
-            let score = self.target.score(&individual.genes);
-            if score < best_score { best_score = score; best = &individual};
+        return keep - 1;
+    }
+
+    /// k-way tournament selection: sample `tournament_k` candidate indices
+    /// from the survivors and return either the single best (`weighted ==
+    /// false`) or a fitness-rank-proportional pick among the candidates
+    /// (`weighted == true`)
+    fn select_k(&mut self, keep: usize, weighted: bool) -> usize {
+        let k = (self.tournament_k as usize).clamp(1, keep);
+        let candidates: Vec<usize> = (0..k).map(|_| self.rng.gen_range(0..keep)).collect();
+
+        if !weighted {
+            return *candidates.iter().min().unwrap();
         }
 
-        return best.genes();
+        let total: f64 = candidates.iter().map(|&idx| (keep - idx) as f64).sum();
+        let mut pick = self.rng.gen_range(0.0..total);
+
+        for &idx in candidates.iter() {
+            let weight = (keep - idx) as f64;
+            if pick < weight { return idx; }
+            pick -= weight;
+        }
+
+        return *candidates.last().unwrap();
     }
 
-    /// crossover two Genes to create child Genes
+    /// crossover two Genes to create child Genes, then apply mutation
     fn crossover(&mut self, p1: &Genes, p2: &Genes) -> Genes {
-        //random crossover
+        let mut c = match self.crossover_method {
+            options::CrossoverMethod::Random => self.crossover_random(p1, p2),
+            options::CrossoverMethod::Barrier => self.crossover_barrier(p1, p2),
+            options::CrossoverMethod::DoubleBarrier => self.crossover_double_barrier(p1, p2),
+        };
+
+        self.mutate(&mut c);
+
+        return c;
+    }
+
+    /// per-bit uniform crossover
+    fn crossover_random(&mut self, p1: &Genes, p2: &Genes) -> Genes {
         let mut c = p1.clone();
 
         for idx in 0..self.n {
@@ -134,24 +381,65 @@ impl<T: Target> Optimizer<T>{
             }
         }
 
-        for idx in 0..self.n {
-            if self.rng.gen_bool(self.mutation_rate) {
-                c.flip(idx);
-            }
+        return c;
+    }
+
+    /// single cut point `c`: bits `[0,c)` from parent1, `[c,n)` from parent2
+    fn crossover_barrier(&mut self, p1: &Genes, p2: &Genes) -> Genes {
+        let cut = self.rng.gen_range(0..self.n);
+        let mut c = p1.clone();
+
+        for idx in cut..self.n {
+            if p2.get(idx) == 1 { c.set(idx); } else { c.clear(idx); }
+        }
+
+        return c;
+    }
+
+    /// two cut points `c1 < c2`: bits `[0,c1)` and `[c2,n)` from parent1,
+    /// `[c1,c2)` from parent2
+    fn crossover_double_barrier(&mut self, p1: &Genes, p2: &Genes) -> Genes {
+        let mut c1 = self.rng.gen_range(0..self.n);
+        let mut c2 = self.rng.gen_range(0..self.n);
+        if c1 > c2 { std::mem::swap(&mut c1, &mut c2); }
+
+        let mut c = p1.clone();
+
+        for idx in c1..c2 {
+            if p2.get(idx) == 1 { c.set(idx); } else { c.clear(idx); }
         }
 
         return c;
     }
+
+    /// flip each bit independently at the rate given by `self.mutation_rate`
+    /// for the current generation and progress history
+    fn mutate(&mut self, genes: &mut Genes) {
+        let rate = self.mutation_rate.current_rate(self.generation, &self.progress_history);
+
+        for idx in 0..self.n {
+            if self.rng.gen_bool(rate) {
+                genes.flip(idx);
+            }
+        }
+    }
 }
 
 pub struct OptimizerBuilder<T: Target> {
     size: Option<u32>,
     n: Option<u32>,
-    mutation_rate: Option<f64>,
+    mutation_rate: Option<MutationRate>,
     target: Option<T>,
     target_type: Option<options::TargetType>,
     selection_method: Option<options::SelectionMethod>,
-    crossover_method: Option<options::CrossoverMethod>
+    crossover_method: Option<options::CrossoverMethod>,
+    tournament_k: Option<u32>,
+    cache: Option<bool>,
+    decoder: Option<Decoder>,
+    #[cfg(feature = "parallel")]
+    parallel: Option<bool>,
+    #[cfg(feature = "parallel")]
+    threads: Option<usize>
 }
 
 impl <T: Target> Default for OptimizerBuilder<T> {
@@ -164,6 +452,13 @@ impl <T: Target> Default for OptimizerBuilder<T> {
             target_type: None,
             selection_method: None,
             crossover_method: None,
+            tournament_k: None,
+            cache: None,
+            decoder: None,
+            #[cfg(feature = "parallel")]
+            parallel: None,
+            #[cfg(feature = "parallel")]
+            threads: None,
         };
     }
 }
@@ -183,8 +478,15 @@ impl <T: Target> OptimizerBuilder<T> {
         return self;
     }
 
+    /// shortcut for `.mutation_rate_schedule(MutationRate::Constant(mutation_rate))`
     pub fn mutation_rate(mut self, mutation_rate: f64) -> OptimizerBuilder<T> {
-        self.mutation_rate = Some(mutation_rate);
+        self.mutation_rate = Some(MutationRate::Constant(mutation_rate));
+        return self;
+    }
+
+    /// vary the per-bit mutation rate by generation and/or observed progress
+    pub fn mutation_rate_schedule(mut self, schedule: MutationRate) -> OptimizerBuilder<T> {
+        self.mutation_rate = Some(schedule);
         return self;
     }
 
@@ -208,6 +510,45 @@ impl <T: Target> OptimizerBuilder<T> {
         return self;
     }
 
+    /// candidate pool size used by `SelectionMethod::EqualTournament` /
+    /// `WeightedTournament`
+    pub fn tournament_k(mut self, tournament_k: u32) -> OptimizerBuilder<T> {
+        self.tournament_k = Some(tournament_k);
+        return self;
+    }
+
+    /// memoize scores by genome bytes so an individual carried over
+    /// unchanged from the last generation isn't re-evaluated; trades memory
+    /// for fewer `Target::score` calls (default: off)
+    pub fn cache(mut self, cache: bool) -> OptimizerBuilder<T> {
+        self.cache = Some(cache);
+        return self;
+    }
+
+    /// attach a bitstring-to-coordinates mapping; not applied during scoring,
+    /// but retrievable via `Optimizer::decoder` so a `Target` can decode a
+    /// genome into real coordinates itself (see `Genes::decode`)
+    pub fn decoder(mut self, decoder: Decoder) -> OptimizerBuilder<T> {
+        self.decoder = Some(decoder);
+        return self;
+    }
+
+    /// toggle rayon-backed parallel fitness evaluation (default: off)
+    #[cfg(feature = "parallel")]
+    pub fn parallel(mut self, parallel: bool) -> OptimizerBuilder<T> {
+        self.parallel = Some(parallel);
+        return self;
+    }
+
+    /// run parallel fitness evaluation on a dedicated `n`-thread rayon pool
+    /// instead of rayon's global pool; implies `.parallel(true)`
+    #[cfg(feature = "parallel")]
+    pub fn threads(mut self, n: usize) -> OptimizerBuilder<T> {
+        self.threads = Some(n);
+        self.parallel = Some(true);
+        return self;
+    }
+
     pub fn build(self) -> Optimizer<T> {
         return Optimizer::new(
             self.size.unwrap_or(DEFAULT_POP_SIZE),
@@ -216,7 +557,14 @@ impl <T: Target> OptimizerBuilder<T> {
             self.target.expect("'target': optimization target must be provided"),
             self.target_type.unwrap_or(DEFAULT_TGT_TYPE),
             self.selection_method.unwrap_or(DEFAULT_SEL_METH),
-            self.crossover_method.unwrap_or(DEFAULT_CRS_METH)
+            self.crossover_method.unwrap_or(DEFAULT_CRS_METH),
+            self.tournament_k.unwrap_or(DEFAULT_TOURNAMENT_K),
+            self.cache.unwrap_or(false),
+            self.decoder,
+            #[cfg(feature = "parallel")]
+            self.parallel.unwrap_or(false),
+            #[cfg(feature = "parallel")]
+            self.threads
         );
     }
 }
@@ -228,6 +576,7 @@ mod tests {
     fn builder_can_build() {
         use crate::{OptimizerBuilder, Target, options};
 
+        #[derive(Clone)]
         struct S {}
 
         impl Target for S {
@@ -243,11 +592,105 @@ mod tests {
             .n(100)
             .mutation_rate(0.1)
             .target(target)
-            .target_type(options::TargetType::MAXIMIZE)
+            .target_type(options::TargetType::Maximize)
             .selection_method(options::SelectionMethod::Weighted)
-            .crossover_method(options::CrossoverMethod::BARRIER)
+            .crossover_method(options::CrossoverMethod::Barrier)
             .build();
+    }
+
+    #[test]
+    fn builder_stores_the_decoder_for_retrieval() {
+        use crate::{OptimizerBuilder, Target, decoder::Decoder, options};
+
+        #[derive(Clone)]
+        struct S {}
+
+        impl Target for S {
+            fn score(&mut self, _: &crate::genes::Genes) -> f64 {
+                return 0.0;
+            }
+        }
+
+        let opt = OptimizerBuilder::new()
+            .size(10)
+            .n(8)
+            .target(S {})
+            .target_type(options::TargetType::Maximize)
+            .decoder(Decoder::new(1, 8, vec![(0.0, 1.0)]))
+            .build();
+
+        let decoder = opt.decoder().expect("decoder was set via the builder");
+        assert_eq!(decoder.n_dim(), 1);
+        assert_eq!(decoder.n_bits_per_group(), 8);
+    }
+
+    #[test]
+    fn cached_score_scores_once_per_distinct_genome() {
+        use crate::{Optimizer, Target, genes::Genes};
+        use std::collections::HashMap;
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        #[derive(Clone)]
+        struct CountingTarget(Arc<AtomicU32>);
+
+        impl Target for CountingTarget {
+            fn score(&mut self, genes: &Genes) -> f64 {
+                self.0.fetch_add(1, Ordering::SeqCst);
+                genes.as_bytes()[0] as f64
+            }
+        }
+
+        let calls = Arc::new(AtomicU32::new(0));
+        let mut target = CountingTarget(calls.clone());
+        let mut cache = Some(HashMap::new());
+
+        let genes = Genes::new_with_genes(vec![7]);
+
+        let first = Optimizer::cached_score(&mut cache, &mut target, &genes);
+        let second = Optimizer::cached_score(&mut cache, &mut target, &genes);
+
+        assert_eq!(first, second);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn step_evicts_cache_entries_for_dropped_individuals() {
+        use crate::{OptimizerBuilder, Target, genes::Genes, options};
+
+        #[derive(Clone)]
+        struct ByteSum {}
+
+        impl Target for ByteSum {
+            fn score(&mut self, genes: &Genes) -> f64 {
+                return genes.as_bytes().iter().map(|&b| b as f64).sum();
+            }
+        }
+
+        let mut opt = OptimizerBuilder::new()
+            .size(10)
+            .n(8)
+            .mutation_rate(0.0)
+            .target(ByteSum {})
+            .target_type(options::TargetType::Maximize)
+            .cache(true)
+            .build();
+
+        opt.step();
+
+        let keep = opt.population.len() / 2;
+        let cache = opt.cache.as_ref().expect("cache was enabled");
+
+        // with only 8 bits of genome (256 possible values) over 10
+        // individuals, duplicate genomes across survivors are expected, so
+        // assert on the *set* of surviving genomes rather than a raw count:
+        // every survivor's genome must still be cached, and nothing else
+        let surviving_genomes: std::collections::HashSet<Vec<u8>> = opt.population[..keep]
+            .iter()
+            .map(|individual| individual.genes().as_bytes().to_vec())
+            .collect();
+        let cached_genomes: std::collections::HashSet<Vec<u8>> = cache.keys().cloned().collect();
 
-        assert!(true);
+        assert_eq!(cached_genomes, surviving_genomes);
     }
 }
@@ -0,0 +1,364 @@
+use rand::{Rng, SeedableRng};
+use rand::rngs::SmallRng;
+
+use crate::genes::Genes;
+use crate::options::TargetType;
+
+const DEFAULT_POP_SIZE: u32 = 100;
+const DEFAULT_ARCHIVE_SIZE: u32 = 50;
+const DEFAULT_MUT_RATE: f64 = 0.05;
+
+/// an optimization target with several simultaneous, possibly competing
+/// objectives (e.g. minimize image error AND minimize number of set genes)
+pub trait MultiTarget {
+    /// the objective values for `genes`, in the same order as `directions()`
+    fn scores(&mut self, genes: &Genes) -> Vec<f64>;
+
+    /// the optimization direction of each objective, same order as `scores()`
+    fn directions(&self) -> Vec<TargetType>;
+}
+
+/// a scored candidate plus its SPEA2 fitness bookkeeping
+struct Candidate {
+    genes: Genes,
+    objectives: Vec<f64>,
+    /// number of other candidates this one dominates
+    strength: u32,
+    /// sum of the strength of every candidate that dominates this one;
+    /// zero iff the candidate is non-dominated
+    raw_fitness: f64,
+    /// `1 / (sigma_k + 2)`, a density estimate in objective space
+    density: f64
+}
+
+impl Candidate {
+    /// SPEA2 fitness: lower is better; non-dominated candidates score below
+    /// 1 (their density is in `(0, 0.5]`), dominated ones at least 1
+    fn fitness(&self) -> f64 {
+        return self.raw_fitness + self.density;
+    }
+}
+
+/// does `a` Pareto-dominate `b`: no worse than `b` in every objective, and
+/// strictly better in at least one
+fn dominates(a: &[f64], b: &[f64], directions: &[TargetType]) -> bool {
+    let mut strictly_better = false;
+
+    for idx in 0..a.len() {
+        let (no_worse, better) = match directions[idx] {
+            TargetType::Minimize => (a[idx] <= b[idx], a[idx] < b[idx]),
+            TargetType::Maximize => (a[idx] >= b[idx], a[idx] > b[idx]),
+        };
+
+        if !no_worse { return false; }
+        if better { strictly_better = true; }
+    }
+
+    return strictly_better;
+}
+
+/// Euclidean distance between two objective vectors
+fn distance(a: &[f64], b: &[f64]) -> f64 {
+    return a.iter().zip(b.iter())
+        .map(|(x, y)| (x - y) * (x - y))
+        .sum::<f64>()
+        .sqrt();
+}
+
+/// SPEA2-style multi-objective optimizer: keeps an external archive of
+/// non-dominated solutions across generations and breeds the next
+/// population from it via binary tournament on SPEA2 fitness
+pub struct MultiOptimizer<T: MultiTarget> {
+    population: Vec<Genes>,
+    archive: Vec<Candidate>,
+    n: u32,
+    size: u32,
+    archive_size: u32,
+    mutation_rate: f64,
+    target: T,
+    rng: SmallRng
+}
+
+impl<T: MultiTarget> MultiOptimizer<T> {
+    fn new(size: u32, archive_size: u32, n: u32, mutation_rate: f64, target: T) -> MultiOptimizer<T> {
+        let mut rng = SmallRng::from_entropy();
+        let mut population = Vec::with_capacity(size as usize);
+
+        for _ in 0..size {
+            let mut genes: Vec<u8> = vec![0u8; (n / 8) as usize];
+            for gene in genes.iter_mut() {
+                *gene = rng.gen();
+            }
+
+            population.push(Genes::new_with_genes(genes));
+        }
+
+        return MultiOptimizer {
+            population,
+            archive: Vec::new(),
+            n,
+            size,
+            archive_size,
+            mutation_rate,
+            target,
+            rng
+        };
+    }
+
+    /// perform a single step of evolution: score the current population and
+    /// archive together, refresh the archive via SPEA2 fitness assignment
+    /// and truncation, then breed the next population from it
+    pub fn step(&mut self) {
+        let directions = self.target.directions();
+
+        let pool: Vec<Genes> = self.archive.drain(..)
+            .map(|c| c.genes)
+            .chain(self.population.drain(..))
+            .collect();
+
+        let mut candidates: Vec<Candidate> = pool.into_iter()
+            .map(|genes| {
+                let objectives = self.target.scores(&genes);
+                Candidate { genes, objectives, strength: 0, raw_fitness: 0.0, density: 0.0 }
+            })
+            .collect();
+
+        Self::assign_fitness(&mut candidates, &directions);
+
+        self.archive = Self::select_archive(candidates, self.archive_size as usize);
+
+        self.population = self.breed(self.size as usize);
+    }
+
+    /// SPEA2 fitness assignment: strength, raw fitness from dominators, and
+    /// a k-th-nearest-neighbor density estimate in objective space
+    fn assign_fitness(candidates: &mut [Candidate], directions: &[TargetType]) {
+        let n = candidates.len();
+        let mut dominates_matrix = vec![vec![false; n]; n];
+
+        for i in 0..n {
+            for j in 0..n {
+                if i == j { continue; }
+                dominates_matrix[i][j] = dominates(&candidates[i].objectives, &candidates[j].objectives, directions);
+            }
+        }
+
+        for i in 0..n {
+            candidates[i].strength = (0..n).filter(|&j| dominates_matrix[i][j]).count() as u32;
+        }
+
+        for i in 0..n {
+            candidates[i].raw_fitness = (0..n)
+                .filter(|&j| dominates_matrix[j][i])
+                .map(|j| candidates[j].strength as f64)
+                .sum();
+        }
+
+        // k is the standard SPEA2 choice: the square root of the pool size
+        let k = (n as f64).sqrt().round() as usize;
+        let k = k.clamp(1, n.saturating_sub(1).max(1));
+
+        for i in 0..n {
+            let mut distances: Vec<f64> = (0..n).filter(|&j| j != i)
+                .map(|j| distance(&candidates[i].objectives, &candidates[j].objectives))
+                .collect();
+            distances.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+            let sigma_k = distances.get(k - 1).copied().unwrap_or(0.0);
+            candidates[i].density = 1.0 / (sigma_k + 2.0);
+        }
+    }
+
+    /// the new archive: every non-dominated candidate (`raw_fitness == 0`),
+    /// padded with the best dominated candidates if there's room, or
+    /// truncated by repeatedly dropping the candidate closest to its
+    /// nearest neighbor if there are too many
+    fn select_archive(mut candidates: Vec<Candidate>, archive_size: usize) -> Vec<Candidate> {
+        candidates.sort_by(|a, b| a.fitness().partial_cmp(&b.fitness()).unwrap());
+
+        let non_dominated = candidates.iter().take_while(|c| c.raw_fitness == 0.0).count();
+
+        if non_dominated <= archive_size {
+            candidates.truncate(archive_size.min(candidates.len()));
+            return candidates;
+        }
+
+        let mut archive = candidates;
+        archive.truncate(non_dominated);
+
+        while archive.len() > archive_size {
+            let closest = (0..archive.len())
+                .min_by(|&i, &j| {
+                    let d_i = Self::nearest_neighbor_distance(&archive, i);
+                    let d_j = Self::nearest_neighbor_distance(&archive, j);
+                    d_i.partial_cmp(&d_j).unwrap()
+                })
+                .unwrap();
+
+            archive.remove(closest);
+        }
+
+        return archive;
+    }
+
+    fn nearest_neighbor_distance(archive: &[Candidate], idx: usize) -> f64 {
+        return (0..archive.len())
+            .filter(|&j| j != idx)
+            .map(|j| distance(&archive[idx].objectives, &archive[j].objectives))
+            .fold(f64::INFINITY, f64::min);
+    }
+
+    /// breed `size` children from the archive via binary tournament
+    /// selection (lower SPEA2 fitness wins) and uniform crossover
+    fn breed(&mut self, size: usize) -> Vec<Genes> {
+        if self.archive.is_empty() {
+            return (0..size).map(|_| {
+                let mut genes: Vec<u8> = vec![0u8; (self.n / 8) as usize];
+                for gene in genes.iter_mut() { *gene = self.rng.gen(); }
+                Genes::new_with_genes(genes)
+            }).collect();
+        }
+
+        let mut children = Vec::with_capacity(size);
+
+        for _ in 0..size {
+            let parent1 = self.tournament_select();
+            let parent2 = self.tournament_select();
+
+            let mut child = self.archive[parent1].genes.clone();
+            for idx in 0..self.n {
+                if self.rng.gen_bool(0.5) {
+                    if self.archive[parent2].genes.get(idx) == 1 { child.set(idx); } else { child.clear(idx); }
+                }
+            }
+
+            for idx in 0..self.n {
+                if self.rng.gen_bool(self.mutation_rate) {
+                    child.flip(idx);
+                }
+            }
+
+            children.push(child);
+        }
+
+        return children;
+    }
+
+    /// binary tournament over the archive on SPEA2 fitness (lower wins)
+    fn tournament_select(&mut self) -> usize {
+        let a = self.rng.gen_range(0..self.archive.len());
+        let b = self.rng.gen_range(0..self.archive.len());
+
+        return if self.archive[a].fitness() <= self.archive[b].fitness() { a } else { b };
+    }
+
+    /// the current approximate Pareto front: the genes of every candidate
+    /// in the external archive
+    pub fn front(&self) -> Vec<&Genes> {
+        return self.archive.iter().map(|c| &c.genes).collect();
+    }
+}
+
+pub struct MultiOptimizerBuilder<T: MultiTarget> {
+    size: Option<u32>,
+    archive_size: Option<u32>,
+    n: Option<u32>,
+    mutation_rate: Option<f64>,
+    target: Option<T>
+}
+
+impl<T: MultiTarget> Default for MultiOptimizerBuilder<T> {
+    fn default() -> Self {
+        return MultiOptimizerBuilder {
+            size: None,
+            archive_size: None,
+            n: None,
+            mutation_rate: None,
+            target: None,
+        };
+    }
+}
+
+impl<T: MultiTarget> MultiOptimizerBuilder<T> {
+    pub fn new() -> MultiOptimizerBuilder<T> {
+        return MultiOptimizerBuilder { ..Default::default() };
+    }
+
+    pub fn size(mut self, size: u32) -> MultiOptimizerBuilder<T> {
+        self.size = Some(size);
+        return self;
+    }
+
+    /// the maximum size of the external non-dominated-solution archive
+    pub fn archive_size(mut self, archive_size: u32) -> MultiOptimizerBuilder<T> {
+        self.archive_size = Some(archive_size);
+        return self;
+    }
+
+    pub fn n(mut self, n: u32) -> MultiOptimizerBuilder<T> {
+        self.n = Some(n);
+        return self;
+    }
+
+    pub fn mutation_rate(mut self, mutation_rate: f64) -> MultiOptimizerBuilder<T> {
+        self.mutation_rate = Some(mutation_rate);
+        return self;
+    }
+
+    pub fn target(mut self, target: T) -> MultiOptimizerBuilder<T> {
+        self.target = Some(target);
+        return self;
+    }
+
+    pub fn build(self) -> MultiOptimizer<T> {
+        return MultiOptimizer::new(
+            self.size.unwrap_or(DEFAULT_POP_SIZE),
+            self.archive_size.unwrap_or(DEFAULT_ARCHIVE_SIZE),
+            self.n.expect("'n': number of genes must be provided"),
+            self.mutation_rate.unwrap_or(DEFAULT_MUT_RATE),
+            self.target.expect("'target': optimization target must be provided"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Dummy;
+    impl MultiTarget for Dummy {
+        fn scores(&mut self, _genes: &Genes) -> Vec<f64> { vec![] }
+        fn directions(&self) -> Vec<TargetType> { vec![] }
+    }
+
+    #[test]
+    fn dominates_respects_direction() {
+        assert!(dominates(&[1.0], &[2.0], &[TargetType::Minimize]));
+        assert!(!dominates(&[2.0], &[1.0], &[TargetType::Minimize]));
+        assert!(dominates(&[2.0], &[1.0], &[TargetType::Maximize]));
+        assert!(!dominates(&[1.0, 2.0], &[1.0, 2.0], &[TargetType::Minimize, TargetType::Minimize]));
+    }
+
+    #[test]
+    fn assign_fitness_ranks_by_dominance_and_density() {
+        let mut candidates = vec![
+            Candidate { genes: Genes::new(8), objectives: vec![1.0], strength: 0, raw_fitness: 0.0, density: 0.0 },
+            Candidate { genes: Genes::new(8), objectives: vec![2.0], strength: 0, raw_fitness: 0.0, density: 0.0 },
+            Candidate { genes: Genes::new(8), objectives: vec![3.0], strength: 0, raw_fitness: 0.0, density: 0.0 },
+        ];
+
+        MultiOptimizer::<Dummy>::assign_fitness(&mut candidates, &[TargetType::Minimize]);
+
+        assert_eq!(candidates[0].raw_fitness, 0.0);
+        assert_eq!(candidates[1].raw_fitness, 2.0);
+        assert_eq!(candidates[2].raw_fitness, 3.0);
+
+        assert!((candidates[0].density - 0.25).abs() < 1e-9);
+        assert!((candidates[1].density - 1.0 / 3.0).abs() < 1e-9);
+        assert!((candidates[2].density - 0.25).abs() < 1e-9);
+
+        // the non-dominated candidate has the best (lowest) SPEA2 fitness
+        assert!(candidates[0].fitness() < candidates[1].fitness());
+        assert!(candidates[0].fitness() < candidates[2].fitness());
+    }
+}
@@ -0,0 +1,110 @@
+use std::collections::VecDeque;
+
+/// per-bit mutation probability, optionally varying with generation and
+/// observed progress so the search can escape premature convergence
+#[derive(Clone, Copy)]
+pub enum MutationRate {
+    /// a fixed per-bit probability, regardless of generation or progress
+    Constant(f64),
+    /// interpolates linearly from `start` to `end` over `over_generations`
+    /// generations, then holds at `end`
+    Linear { start: f64, end: f64, over_generations: u32 },
+    /// `base_rate` by default; as the best score stalls (its change between
+    /// consecutive generations stays below `stall_threshold`) over the last
+    /// `stall_generations` generations, the rate rises smoothly toward
+    /// `max_rate`, decaying back toward `base_rate` once progress resumes
+    ProgressScaled { base_rate: f64, max_rate: f64, stall_threshold: f64, stall_generations: usize }
+}
+
+impl MutationRate {
+    /// the per-bit mutation probability to use for `generation`, given the
+    /// best score observed each generation so far (oldest first)
+    pub fn current_rate(&self, generation: u32, progress_history: &VecDeque<f64>) -> f64 {
+        let rate = match self {
+            MutationRate::Constant(rate) => *rate,
+            MutationRate::Linear { start, end, over_generations } => {
+                let t = if *over_generations == 0 {
+                    1.0
+                } else {
+                    (generation as f64 / *over_generations as f64).min(1.0)
+                };
+
+                start + t * (end - start)
+            },
+            MutationRate::ProgressScaled { base_rate, max_rate, stall_threshold, stall_generations } => {
+                if progress_history.len() <= *stall_generations {
+                    *base_rate
+                } else {
+                    let recent: Vec<f64> = progress_history.iter().rev()
+                        .take(stall_generations + 1)
+                        .copied()
+                        .collect();
+
+                    let stalled = recent.windows(2)
+                        .filter(|w| (w[0] - w[1]).abs() < *stall_threshold)
+                        .count();
+                    let stalled_frac = stalled as f64 / (recent.len() - 1) as f64;
+
+                    base_rate + stalled_frac * (max_rate - base_rate)
+                }
+            },
+        };
+
+        return rate.clamp(0.0, 1.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constant_ignores_generation_and_progress() {
+        let rate = MutationRate::Constant(0.3);
+        let history = VecDeque::from(vec![1.0, 2.0, 3.0]);
+
+        assert_eq!(rate.current_rate(0, &history), 0.3);
+        assert_eq!(rate.current_rate(1000, &history), 0.3);
+    }
+
+    #[test]
+    fn linear_interpolates_then_holds_at_end() {
+        let rate = MutationRate::Linear { start: 0.1, end: 0.5, over_generations: 10 };
+        let history = VecDeque::new();
+
+        assert!((rate.current_rate(0, &history) - 0.1).abs() < 1e-9);
+        assert!((rate.current_rate(5, &history) - 0.3).abs() < 1e-9);
+        assert!((rate.current_rate(10, &history) - 0.5).abs() < 1e-9);
+        assert!((rate.current_rate(20, &history) - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn progress_scaled_uses_base_rate_before_enough_history() {
+        let rate = MutationRate::ProgressScaled {
+            base_rate: 0.05, max_rate: 0.5, stall_threshold: 0.01, stall_generations: 3
+        };
+        let history = VecDeque::from(vec![1.0, 1.0]);
+
+        assert_eq!(rate.current_rate(0, &history), 0.05);
+    }
+
+    #[test]
+    fn progress_scaled_rises_to_max_rate_when_fully_stalled() {
+        let rate = MutationRate::ProgressScaled {
+            base_rate: 0.05, max_rate: 0.5, stall_threshold: 0.01, stall_generations: 3
+        };
+        let history = VecDeque::from(vec![1.0, 1.0, 1.0, 1.0]);
+
+        assert!((rate.current_rate(0, &history) - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn progress_scaled_stays_at_base_rate_while_improving() {
+        let rate = MutationRate::ProgressScaled {
+            base_rate: 0.05, max_rate: 0.5, stall_threshold: 0.01, stall_generations: 3
+        };
+        let history = VecDeque::from(vec![1.0, 2.0, 3.0, 4.0]);
+
+        assert!((rate.current_rate(0, &history) - 0.05).abs() < 1e-9);
+    }
+}
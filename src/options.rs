@@ -1,14 +1,18 @@
+#[derive(Clone, Copy)]
 pub enum TargetType {
     Minimize,
     Maximize
 }
 
+#[derive(Clone, Copy)]
 pub enum SelectionMethod {
     Equal,
     EqualTournament,
     Weighted,
     WeightedTournament
 }
+
+#[derive(Clone, Copy)]
 pub enum CrossoverMethod {
     Random,
     Barrier,
@@ -0,0 +1,141 @@
+use std::collections::VecDeque;
+
+use crate::options::TargetType;
+
+/// decides when `Optimizer::run` should stop evolving. `progress_history`
+/// holds the best score observed each generation so far, oldest first.
+pub trait StopCriterion {
+    fn should_stop(&mut self, generation: u32, best_score: f64, progress_history: &VecDeque<f64>) -> bool;
+}
+
+/// stop after a fixed number of generations
+pub struct Generations(pub u32);
+
+impl StopCriterion for Generations {
+    fn should_stop(&mut self, generation: u32, _best_score: f64, _progress_history: &VecDeque<f64>) -> bool {
+        return generation >= self.0;
+    }
+}
+
+/// stop once the best score reaches `target`, in the direction implied by
+/// `target_type` (at or below for `Minimize`, at or above for `Maximize`)
+pub struct FitnessThreshold {
+    target: f64,
+    target_type: TargetType
+}
+
+impl FitnessThreshold {
+    pub fn new(target: f64, target_type: TargetType) -> FitnessThreshold {
+        return FitnessThreshold { target, target_type };
+    }
+}
+
+impl StopCriterion for FitnessThreshold {
+    fn should_stop(&mut self, _generation: u32, best_score: f64, _progress_history: &VecDeque<f64>) -> bool {
+        return match self.target_type {
+            TargetType::Minimize => best_score <= self.target,
+            TargetType::Maximize => best_score >= self.target,
+        };
+    }
+}
+
+/// stop once the population has converged: the absolute least-squares slope
+/// of the best score over the last `window` generations stays below
+/// `epsilon` for `window` consecutive generations
+pub struct ProgressSaturation {
+    window: usize,
+    epsilon: f64,
+    /// number of consecutive `should_stop` calls so far where the absolute
+    /// slope stayed below `epsilon`; reset to zero on any call that doesn't
+    streak: u32
+}
+
+impl ProgressSaturation {
+    pub fn new(window: usize, epsilon: f64) -> ProgressSaturation {
+        return ProgressSaturation { window, epsilon, streak: 0 };
+    }
+
+    /// slope = cov(generation, best_score) / var(generation) over the last
+    /// `self.window` entries of `progress_history`
+    fn slope(&self, progress_history: &VecDeque<f64>) -> f64 {
+        let ys: Vec<f64> = progress_history.iter().rev().take(self.window).copied().collect();
+        let n = ys.len() as f64;
+
+        // ys runs newest first, so x counts down from n - 1 to keep the
+        // generations in chronological order (oldest at x = 0)
+        let mean_x = (n - 1.0) / 2.0;
+        let mean_y = ys.iter().sum::<f64>() / n;
+
+        let mut cov = 0.0;
+        let mut var = 0.0;
+        for (idx, y) in ys.iter().enumerate() {
+            let x = (n - 1.0) - idx as f64;
+            cov += (x - mean_x) * (y - mean_y);
+            var += (x - mean_x) * (x - mean_x);
+        }
+
+        if var == 0.0 { return 0.0; }
+
+        return cov / var;
+    }
+}
+
+impl StopCriterion for ProgressSaturation {
+    fn should_stop(&mut self, _generation: u32, _best_score: f64, progress_history: &VecDeque<f64>) -> bool {
+        if progress_history.len() < self.window {
+            self.streak = 0;
+            return false;
+        }
+
+        if self.slope(progress_history).abs() < self.epsilon {
+            self.streak += 1;
+        } else {
+            self.streak = 0;
+        }
+
+        return self.streak as usize >= self.window;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slope_sign_matches_trend() {
+        let psat = ProgressSaturation::new(3, 1e-9);
+        let history = VecDeque::from(vec![1.0, 2.0, 3.0]);
+
+        let slope = psat.slope(&history);
+        assert!(slope > 0.0, "expected a positive slope for an increasing sequence, got {slope}");
+        assert!((slope.abs() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn slope_is_zero_for_a_flat_window() {
+        let psat = ProgressSaturation::new(3, 1e-9);
+        let history = VecDeque::from(vec![5.0, 5.0, 5.0]);
+
+        assert_eq!(psat.slope(&history), 0.0);
+    }
+
+    #[test]
+    fn stops_only_after_window_consecutive_flat_windows() {
+        let mut psat = ProgressSaturation::new(2, 1e-9);
+        let history = VecDeque::from(vec![5.0, 5.0]);
+
+        assert!(!psat.should_stop(0, 5.0, &history));
+        assert!(psat.should_stop(0, 5.0, &history));
+    }
+
+    #[test]
+    fn an_improving_window_resets_the_streak() {
+        let mut psat = ProgressSaturation::new(2, 1e-9);
+        let flat = VecDeque::from(vec![5.0, 5.0]);
+        let improving = VecDeque::from(vec![5.0, 6.0]);
+
+        assert!(!psat.should_stop(0, 5.0, &flat));
+        assert!(!psat.should_stop(0, 6.0, &improving));
+        assert!(!psat.should_stop(0, 5.0, &flat));
+    }
+}